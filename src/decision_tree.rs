@@ -0,0 +1,180 @@
+use std::collections::HashMap;
+
+use stellar_xdr::curr::{
+    ContractEvent, ContractEventBody, ScSpecEntry, ScSpecEventParamLocationV0, ScVal,
+};
+
+use crate::{event_matches_spec, score_spec_match, SpecRegistry};
+
+/// A compiled index over a set of specs, keyed by leading prefix-topic symbol and
+/// total topic arity. Classifying an event walks down to a small candidate set
+/// instead of scanning every loaded spec, the way a match-compiler builds a
+/// discrimination trie over a set of patterns.
+///
+/// The trie deliberately stops at topic arity and doesn't also key on a
+/// param-type signature: `sc_spec_type_to_sc_val_type` only has a spec type's
+/// outer discriminant to work with, so it collapses `Option<T>` to `Void` and
+/// UDT enums/unions to `Map` rather than `T`'s or the union's real wire
+/// discriminant. Gating candidacy on that approximate signature would silently
+/// drop specs that the precise `event_matches_spec`/`sc_val_matches_spec_type`
+/// check (which has the full `ScSpecTypeDef`, not just its discriminant) would
+/// have matched. Leading symbol + arity is cheap, exact, and never over-prunes.
+pub struct DecisionTree {
+    // leading topic symbol -> topic arity -> spec indices
+    trie: HashMap<Option<String>, HashMap<usize, Vec<usize>>>,
+}
+
+impl DecisionTree {
+    pub fn build(specs: &[ScSpecEntry]) -> Self {
+        let mut trie: HashMap<Option<String>, HashMap<usize, Vec<usize>>> = HashMap::new();
+
+        for (i, spec_entry) in specs.iter().enumerate() {
+            let ScSpecEntry::EventV0(spec) = spec_entry else {
+                continue;
+            };
+
+            let leading_symbol = spec.prefix_topics.first().map(|s| s.to_string());
+            let topic_arity = spec.prefix_topics.len()
+                + spec
+                    .params
+                    .iter()
+                    .filter(|p| p.location == ScSpecEventParamLocationV0::TopicList)
+                    .count();
+
+            trie.entry(leading_symbol)
+                .or_default()
+                .entry(topic_arity)
+                .or_default()
+                .push(i);
+        }
+
+        Self { trie }
+    }
+
+    /// Returns the indices (into the `specs` slice passed to [`DecisionTree::build`])
+    /// of the specs whose leading topic symbol and topic arity line up with
+    /// `event`'s, plus the specs with no prefix topics and the same arity. This is
+    /// the candidate set that full matching/scoring should be run against.
+    pub fn candidates(&self, event: &ContractEvent) -> Vec<usize> {
+        let ContractEventBody::V0(body) = &event.body;
+
+        let leading_symbol = match body.topics.first() {
+            Some(ScVal::Symbol(s)) => Some(s.to_string()),
+            Some(ScVal::String(s)) => Some(s.to_string()),
+            _ => None,
+        };
+        let topic_arity = body.topics.len();
+
+        let mut candidates: Vec<usize> = self
+            .trie
+            .get(&leading_symbol)
+            .and_then(|by_arity| by_arity.get(&topic_arity))
+            .cloned()
+            .unwrap_or_default();
+
+        // Specs without prefix topics are keyed under `None`, but an event whose
+        // first topic is a symbol param can still match them.
+        if leading_symbol.is_some() {
+            if let Some(unprefixed) = self.trie.get(&None).and_then(|by_arity| by_arity.get(&topic_arity)) {
+                candidates.extend(unprefixed);
+                candidates.sort_unstable();
+            }
+        }
+
+        candidates
+    }
+}
+
+/// The result of classifying an event against a compiled [`DecisionTree`].
+pub enum Classification {
+    /// No candidate spec matched the event.
+    NoMatch,
+    /// Exactly one candidate had the highest specificity score.
+    Matched { spec_index: usize },
+    /// More than one candidate tied for the highest specificity score; the caller
+    /// should surface this as an error rather than silently picking one.
+    Ambiguous { spec_indices: Vec<usize> },
+}
+
+/// Classifies `event` against `specs` using `tree` to narrow the candidate set,
+/// `event_matches_spec` to filter it, and `score_spec_match` to rank survivors.
+pub fn classify(
+    tree: &DecisionTree,
+    event: &ContractEvent,
+    specs: &[ScSpecEntry],
+    registry: &SpecRegistry,
+    strict: bool,
+) -> Classification {
+    let matches: Vec<(usize, i32)> = tree
+        .candidates(event)
+        .into_iter()
+        .filter(|&i| event_matches_spec(event, &specs[i], registry, strict))
+        .map(|i| (i, score_spec_match(event, &specs[i], registry)))
+        .collect();
+
+    let Some(&best_score) = matches.iter().map(|(_, score)| score).max() else {
+        return Classification::NoMatch;
+    };
+
+    let winners: Vec<usize> = matches
+        .into_iter()
+        .filter(|&(_, score)| score == best_score)
+        .map(|(i, _)| i)
+        .collect();
+
+    if winners.len() == 1 {
+        Classification::Matched {
+            spec_index: winners[0],
+        }
+    } else {
+        Classification::Ambiguous {
+            spec_indices: winners,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use stellar_xdr::curr::{ScSpecEventDataFormat, ScSpecTypeDef};
+
+    use super::*;
+    use crate::test_fixtures::{contract_event, event_spec, param, symbol};
+
+    #[test]
+    fn two_specs_with_the_same_shape_tie_and_are_reported_ambiguous() {
+        let specs = vec![
+            event_spec(
+                "evt_a",
+                &["evt"],
+                vec![param(
+                    "amount",
+                    ScSpecTypeDef::U32,
+                    ScSpecEventParamLocationV0::Data,
+                )],
+                ScSpecEventDataFormat::SingleValue,
+            ),
+            event_spec(
+                "evt_b",
+                &["evt"],
+                vec![param(
+                    "amount",
+                    ScSpecTypeDef::U32,
+                    ScSpecEventParamLocationV0::Data,
+                )],
+                ScSpecEventDataFormat::SingleValue,
+            ),
+        ];
+        let tree = DecisionTree::build(&specs);
+        let event = contract_event(vec![ScVal::Symbol(symbol("evt"))], ScVal::U32(5));
+        let registry = HashMap::new();
+
+        match classify(&tree, &event, &specs, &registry, false) {
+            Classification::Ambiguous { spec_indices } => {
+                assert_eq!(spec_indices, vec![0, 1]);
+            },
+            _ => panic!("expected an ambiguous match between two equally-specific specs"),
+        }
+    }
+}
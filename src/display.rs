@@ -0,0 +1,110 @@
+use std::collections::HashMap;
+
+use stellar_xdr::curr::{
+    ContractEvent, ContractEventBody, ScSpecEntry, ScSpecEventDataFormat,
+    ScSpecEventParamLocationV0, ScSpecTypeDef, ScVal,
+};
+use serde_json::Value as JsonValue;
+
+use crate::build_spec_registry;
+use crate::typed_json::scval_to_typed_json;
+use crate::SpecRegistry;
+
+/// Renders a matched event as a single human-readable line, e.g.
+/// `transfer(from=GABC.., to=GDEF.., amount=100i128)`, mirroring `ethers`'
+/// `EthDisplay` derive for Soroban events.
+pub fn format_event(event: &ContractEvent, spec_entry: &ScSpecEntry, specs: &[ScSpecEntry]) -> String {
+    let registry = build_spec_registry(specs);
+    let ContractEventBody::V0(event_body) = &event.body;
+    let spec = match spec_entry {
+        ScSpecEntry::EventV0(spec) => spec,
+        _ => return "<spec is not an EventV0>".to_string(),
+    };
+
+    let topics = &event_body.topics;
+    let skip_topics = spec.prefix_topics.len();
+
+    // Same topic/map/vec extraction `generate_derived_json` uses, so display output
+    // and JSON output agree on which raw value backs each param.
+    let mut map_data_entries: HashMap<String, &ScVal> = HashMap::new();
+    if let ScVal::Map(Some(entries)) = &event_body.data {
+        for entry in entries.iter() {
+            if let ScVal::Symbol(key) = &entry.key {
+                map_data_entries.insert(key.to_string(), &entry.val);
+            }
+        }
+    }
+    let mut vec_data_entries: Vec<&ScVal> = Vec::new();
+    if let ScVal::Vec(Some(entries)) = &event_body.data {
+        vec_data_entries = entries.iter().collect();
+    }
+
+    let mut topic_param_count = 0;
+    let mut data_param_count = 0;
+    let mut args = Vec::new();
+
+    for param in spec.params.iter() {
+        let param_name = param.name.to_string();
+        let value = match param.location {
+            ScSpecEventParamLocationV0::TopicList => {
+                let idx = skip_topics + topic_param_count;
+                topic_param_count += 1;
+                topics.get(idx)
+            },
+            ScSpecEventParamLocationV0::Data => match spec.data_format {
+                ScSpecEventDataFormat::SingleValue => Some(&event_body.data),
+                ScSpecEventDataFormat::Map => map_data_entries.get(&param_name).copied(),
+                ScSpecEventDataFormat::Vec => {
+                    let v = vec_data_entries.get(data_param_count).copied();
+                    data_param_count += 1;
+                    v
+                },
+            },
+            _ => None,
+        };
+
+        let rendered = match value {
+            Some(v) => format_value(v, &param.type_, &registry),
+            None => "<missing>".to_string(),
+        };
+        args.push(format!("{param_name}={rendered}"));
+    }
+
+    format!("{}({})", spec.name, args.join(", "))
+}
+
+/// Renders a single value the way a signature line would: 32-, 64- and 128-bit
+/// integers keep their XDR type as a suffix (`100i128`), strings/symbols/addresses
+/// are printed bare, bytes as hex, and everything else (containers, UDTs, 256-bit
+/// integers, timepoints, durations) falls back to the compact typed-JSON rendering.
+fn format_value(val: &ScVal, ty: &ScSpecTypeDef, specs: &SpecRegistry) -> String {
+    match (val, ty) {
+        (ScVal::Bool(b), ScSpecTypeDef::Bool) => b.to_string(),
+        (ScVal::Void, ScSpecTypeDef::Void) => "()".to_string(),
+        (ScVal::U32(n), ScSpecTypeDef::U32) => format!("{n}u32"),
+        (ScVal::I32(n), ScSpecTypeDef::I32) => format!("{n}i32"),
+        (ScVal::U64(n), ScSpecTypeDef::U64) => format!("{n}u64"),
+        (ScVal::I64(n), ScSpecTypeDef::I64) => format!("{n}i64"),
+        (ScVal::U128(n), ScSpecTypeDef::U128) => {
+            format!("{}u128", ((n.hi as u128) << 64) | n.lo as u128)
+        },
+        (ScVal::I128(n), ScSpecTypeDef::I128) => {
+            format!("{}i128", ((n.hi as i128) << 64) | n.lo as i128)
+        },
+        (ScVal::Symbol(s), ScSpecTypeDef::Symbol) => s.to_string(),
+        (ScVal::String(s), ScSpecTypeDef::String) => s.to_string(),
+        (ScVal::Bytes(b), ScSpecTypeDef::Bytes | ScSpecTypeDef::BytesN(_)) => {
+            hex::encode(b.as_slice())
+        },
+        (ScVal::Address(_), ScSpecTypeDef::Address) => match scval_to_typed_json(val, ty, specs) {
+            JsonValue::String(s) => s,
+            other => other.to_string(),
+        },
+        (_, ScSpecTypeDef::Option(option_spec)) => match val {
+            ScVal::Void => "None".to_string(),
+            _ => format_value(val, &option_spec.value_type, specs),
+        },
+        // Containers and UDTs: fall back to the compact typed-JSON rendering.
+        _ => scval_to_typed_json(val, ty, specs).to_string(),
+    }
+}
@@ -1,57 +1,147 @@
+mod bindings;
+mod decision_tree;
+mod display;
+mod param_types;
+#[cfg(test)]
+mod test_fixtures;
+mod typed_json;
+
 use std::fs::File;
 use std::path::PathBuf;
 use std::collections::HashMap;
 
-use clap::Parser;
+use clap::{Parser, ValueEnum};
 use stellar_xdr::curr::{
-    ContractEvent, ContractEventBody, ScSpecEntry, ScSpecEventDataFormat, ScSpecEventParamLocationV0, ScSpecEventParamV0, ScSpecTypeDef, ScVal
+    ContractEvent, ContractEventBody, ScSpecEntry, ScSpecEventDataFormat, ScSpecEventParamLocationV0, ScSpecEventParamV0, ScSpecTypeDef, ScSpecUdtUnionCaseV0, ScVal
 };
 use serde_json::{json, Value as JsonValue};
 
+use bindings::generate_bindings;
+use decision_tree::{Classification, DecisionTree};
+use display::format_event;
+use typed_json::scval_to_typed_json;
+
 #[derive(Parser, Debug)]
 #[command(author, version, about, long_about = None)]
 struct Args {
-    #[arg(long)]
-    event: PathBuf,
+    #[arg(long, required_unless_present = "generate")]
+    event: Option<PathBuf>,
 
     #[arg(long = "spec")]
     specs: Vec<PathBuf>,
+
+    /// Instead of matching an event, generate a Rust module of typed event bindings
+    /// (one struct + `TryFrom<&ContractEvent>` impl per spec) and write it to this path.
+    #[arg(long)]
+    generate: Option<PathBuf>,
+
+    /// Whether to render decoded param values as raw XDR-JSON (the legacy, opaque
+    /// encoding) or as human-readable JSON shaped by the matching spec's types.
+    #[arg(long, value_enum, default_value_t = Format::XdrJson)]
+    format: Format,
+
+    /// Reject the lenient Map/Vec partial matches: every non-Option data param key
+    /// must be present with the correct type, extra Map keys are forbidden, and
+    /// `Vec` data must have exactly as many elements as the spec declares params.
+    #[arg(long)]
+    strict: bool,
+}
+
+/// Output encoding for decoded event param values.
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+enum Format {
+    /// The legacy `serde_json::to_value(&scval)` encoding, e.g. `{"u32":5}`.
+    #[value(name = "xdr-json")]
+    XdrJson,
+    /// Self-describing JSON shaped by the spec's types, e.g. `5` or `"GABC..."`.
+    #[value(name = "typed-json")]
+    TypedJson,
+    /// A single human-readable line, e.g. `transfer(from=GABC.., amount=100i128)`.
+    #[value(name = "display")]
+    Display,
 }
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
     let args = Args::parse();
-    let event = args.event()?;
     let specs = args.specs()?;
-    
-    let mut found_match = false;
-    for (i, spec_entry) in specs.iter().enumerate() {
-        if event_matches_spec(&event, spec_entry) {
-            // Found a match!
-            let spec_path = &args.specs[i];
-            println!("Found matching spec: {}", spec_path.display());
-            
-            // Generate the derived JSON object
-            let derived_json = generate_derived_json(&event, spec_entry);
-            
-            // Output the derived JSON
-            println!("\nDerived JSON:");
-            let formatted_json = serde_json::to_string_pretty(&derived_json)?;
-            println!("{}", formatted_json);
-            
-            found_match = true;
-            break;  // Use the first matching spec
-        }
+
+    if let Some(out_path) = &args.generate {
+        let bindings = generate_bindings(&specs);
+        std::fs::write(out_path, bindings)?;
+        println!("Wrote generated bindings to {}", out_path.display());
+        return Ok(());
     }
 
-    if !found_match {
-        println!("No matching spec found for the event.");
+    let event = args.event()?;
+    let spec_registry = build_spec_registry(&specs);
+    let tree = DecisionTree::build(&specs);
+
+    match decision_tree::classify(&tree, &event, &specs, &spec_registry, args.strict) {
+        Classification::Matched { spec_index } => {
+            let spec_path = &args.specs[spec_index];
+            println!("Found matching spec: {}", spec_path.display());
+
+            if args.format == Format::Display {
+                println!("{}", format_event(&event, &specs[spec_index], &specs));
+            } else {
+                let derived_json = generate_derived_json(
+                    &event,
+                    &specs[spec_index],
+                    args.format,
+                    &spec_registry,
+                    args.strict,
+                );
+
+                println!("\nDerived JSON:");
+                let formatted_json = serde_json::to_string_pretty(&derived_json)?;
+                println!("{}", formatted_json);
+            }
+        },
+        Classification::Ambiguous { spec_indices } => {
+            eprintln!("Ambiguous match: the event matches more than one spec with equal specificity:");
+            for i in spec_indices {
+                eprintln!("  - {}", args.specs[i].display());
+            }
+            return Err("ambiguous spec match".into());
+        },
+        Classification::NoMatch => {
+            println!("No matching spec found for the event.");
+        },
     }
 
     Ok(())
 }
 
-// Helper function to check if an ScVal's type matches a ScSpecTypeDef
-fn sc_val_matches_spec_type(val: &ScVal, spec_type: &ScSpecTypeDef) -> bool {
+/// A registry of every loaded spec, keyed by the name of the UDT (struct, union,
+/// enum, or error enum) it defines, so that `ScSpecTypeDef::Udt` references can be
+/// resolved across spec files.
+pub(crate) type SpecRegistry<'a> = HashMap<String, &'a ScSpecEntry>;
+
+/// Builds a [`SpecRegistry`] from every loaded spec entry that defines a named UDT.
+pub(crate) fn build_spec_registry(specs: &[ScSpecEntry]) -> SpecRegistry<'_> {
+    let mut registry = HashMap::new();
+    for spec in specs {
+        let name = match spec {
+            ScSpecEntry::UdtStructV0(s) => Some(s.name.to_string()),
+            ScSpecEntry::UdtUnionV0(u) => Some(u.name.to_string()),
+            ScSpecEntry::UdtEnumV0(e) => Some(e.name.to_string()),
+            ScSpecEntry::UdtErrorEnumV0(e) => Some(e.name.to_string()),
+            _ => None,
+        };
+        if let Some(name) = name {
+            registry.insert(name, spec);
+        }
+    }
+    registry
+}
+
+// Helper function to check if an ScVal's type matches a ScSpecTypeDef, recursing into
+// nested containers, tuples, and user-defined types the way a dynamic decoder would.
+fn sc_val_matches_spec_type(
+    val: &ScVal,
+    spec_type: &ScSpecTypeDef,
+    specs: &SpecRegistry,
+) -> bool {
     match (val, spec_type) {
         // Simple scalar types
         (ScVal::Bool(_), ScSpecTypeDef::Bool) => true,
@@ -70,13 +160,29 @@ fn sc_val_matches_spec_type(val: &ScVal, spec_type: &ScSpecTypeDef) -> bool {
         (ScVal::String(_), ScSpecTypeDef::String) => true,
         (ScVal::Bytes(_), ScSpecTypeDef::Bytes) => true,
 
-        // Container types (check outer type only)
-        (ScVal::Vec(Some(_)), ScSpecTypeDef::Vec(_)) => true,
-        (ScVal::Map(Some(_)), ScSpecTypeDef::Map(_)) => true,
+        // Vec: every element must match the element type
+        (ScVal::Vec(Some(elements)), ScSpecTypeDef::Vec(vec_spec)) => elements
+            .iter()
+            .all(|e| sc_val_matches_spec_type(e, &vec_spec.element_type, specs)),
+
+        // Map: every key and value must match the map's key/value types
+        (ScVal::Map(Some(entries)), ScSpecTypeDef::Map(map_spec)) => entries.iter().all(|entry| {
+            sc_val_matches_spec_type(&entry.key, &map_spec.key_type, specs)
+                && sc_val_matches_spec_type(&entry.val, &map_spec.value_type, specs)
+        }),
+
+        // Tuple: encoded as a Vec with exactly one element per tuple position
+        (ScVal::Vec(Some(elements)), ScSpecTypeDef::Tuple(tuple_spec)) => {
+            elements.len() == tuple_spec.value_types.len()
+                && elements
+                    .iter()
+                    .zip(tuple_spec.value_types.iter())
+                    .all(|(e, t)| sc_val_matches_spec_type(e, t, specs))
+        },
 
         // Option type
         (_, ScSpecTypeDef::Option(option_spec)) => {
-            sc_val_matches_spec_type(val, &option_spec.value_type)
+            sc_val_matches_spec_type(val, &option_spec.value_type, specs)
         },
 
         // BytesN type
@@ -84,12 +190,92 @@ fn sc_val_matches_spec_type(val: &ScVal, spec_type: &ScSpecTypeDef) -> bool {
             bytes.len() == bytes_n_spec.n as usize
         },
 
+        // User-defined type: resolve the name in the spec registry and validate the
+        // value's shape against the struct/union/enum definition.
+        (_, ScSpecTypeDef::Udt(udt_spec)) => {
+            match specs.get(&udt_spec.name.to_string()) {
+                Some(udt_entry) => sc_val_matches_udt(val, udt_entry, specs),
+                None => false, // UDT spec not loaded; can't validate
+            }
+        },
+
         // Any other combination is a mismatch
         _ => false,
     }
 }
 
-fn event_matches_spec(event: &ContractEvent, spec_entry: &ScSpecEntry) -> bool {
+/// Validates `val` against a resolved UDT spec entry (struct, union, enum, or error enum).
+fn sc_val_matches_udt(val: &ScVal, udt_entry: &ScSpecEntry, specs: &SpecRegistry) -> bool {
+    match udt_entry {
+        ScSpecEntry::UdtStructV0(s) => match val {
+            ScVal::Map(Some(entries)) => {
+                let field_map: HashMap<String, &ScVal> = entries
+                    .iter()
+                    .filter_map(|e| match &e.key {
+                        ScVal::Symbol(k) => Some((k.to_string(), &e.val)),
+                        _ => None,
+                    })
+                    .collect();
+
+                s.fields.iter().all(|field| {
+                    match field_map.get(&field.name.to_string()) {
+                        Some(v) => sc_val_matches_spec_type(v, &field.type_, specs),
+                        None => matches!(field.type_, ScSpecTypeDef::Option(_)),
+                    }
+                })
+            },
+            // Tuple structs (numbered fields) are encoded as a positional `Vec`
+            // instead of a `Map`, the same way `ScSpecTypeDef::Tuple` is.
+            ScVal::Vec(Some(elements)) => {
+                elements.len() == s.fields.len()
+                    && elements
+                        .iter()
+                        .zip(s.fields.iter())
+                        .all(|(e, field)| sc_val_matches_spec_type(e, &field.type_, specs))
+            },
+            _ => false,
+        },
+        ScSpecEntry::UdtUnionV0(u) => {
+            let ScVal::Vec(Some(elements)) = val else {
+                return false;
+            };
+            let Some(ScVal::Symbol(case_name)) = elements.first() else {
+                return false;
+            };
+            let case_name = case_name.to_string();
+
+            u.cases.iter().any(|case| match case {
+                ScSpecUdtUnionCaseV0::VoidV0(c) => {
+                    c.name.to_string() == case_name && elements.len() == 1
+                },
+                ScSpecUdtUnionCaseV0::TupleV0(c) => {
+                    c.name.to_string() == case_name
+                        && elements.len() == 1 + c.type_.len()
+                        && elements[1..]
+                            .iter()
+                            .zip(c.type_.iter())
+                            .all(|(e, t)| sc_val_matches_spec_type(e, t, specs))
+                },
+            })
+        },
+        ScSpecEntry::UdtEnumV0(e) => match val {
+            ScVal::U32(n) => e.cases.iter().any(|c| c.value == *n),
+            _ => false,
+        },
+        ScSpecEntry::UdtErrorEnumV0(e) => match val {
+            ScVal::U32(n) => e.cases.iter().any(|c| c.value == *n),
+            _ => false,
+        },
+        _ => false, // Not a UDT-defining spec entry
+    }
+}
+
+pub(crate) fn event_matches_spec(
+    event: &ContractEvent,
+    spec_entry: &ScSpecEntry,
+    specs: &SpecRegistry,
+    strict: bool,
+) -> bool {
     // Extract the V0 variant from the event body
     let ContractEventBody::V0(event_body) = &event.body;
 
@@ -151,7 +337,7 @@ fn event_matches_spec(event: &ContractEvent, spec_entry: &ScSpecEntry) -> bool {
         let topic_index = prefix_topics.len() + i;
         let event_topic_val = &topics[topic_index];
 
-        if !sc_val_matches_spec_type(event_topic_val, &param.type_) {
+        if !sc_val_matches_spec_type(event_topic_val, &param.type_, specs) {
             return false; // Type mismatch for a topic parameter
         }
     }
@@ -166,7 +352,7 @@ fn event_matches_spec(event: &ContractEvent, spec_entry: &ScSpecEntry) -> bool {
             let param = data_params[0]; // Get the single expected data parameter
 
             // Check if the event's data field matches the type specified by the param
-            if !sc_val_matches_spec_type(&event_body.data, &param.type_) {
+            if !sc_val_matches_spec_type(&event_body.data, &param.type_, specs) {
                 return false; // Type mismatch for single data value
             }
         },
@@ -198,23 +384,30 @@ fn event_matches_spec(event: &ContractEvent, spec_entry: &ScSpecEntry) -> bool {
             let mut matching_keys = 0;
             for (key, expected_type) in &data_param_spec_map {
                 if let Some(value) = event_map.get(key) {
-                    if sc_val_matches_spec_type(value, expected_type) {
+                    if sc_val_matches_spec_type(value, expected_type, specs) {
                         matching_keys += 1;
                     } else {
                         return false; // Type mismatch for key
                     }
-                } else {
-                    // Key not found in event, check if it's an Option type (optional parameter)
-                    if let ScSpecTypeDef::Option(_) = expected_type {
-                        // Optional parameter is allowed to be missing
-                        matching_keys += 1;
-                    }
-                    // For non-Option types, missing keys are allowed in our lenient mapping approach
+                } else if let ScSpecTypeDef::Option(_) = expected_type {
+                    // Optional parameter is allowed to be missing
+                    matching_keys += 1;
+                } else if strict {
+                    return false; // Strict mode: every non-Option key must be present
                 }
+                // Lenient mode: missing non-Option keys are allowed.
             }
 
-            // We consider it a match if we have at least one matching key
-            if matching_keys == 0 {
+            if strict {
+                // Strict mode forbids extra event map keys with no corresponding spec param.
+                if event_map
+                    .keys()
+                    .any(|k| !data_param_spec_map.contains_key(k))
+                {
+                    return false;
+                }
+            } else if matching_keys == 0 {
+                // Lenient mode: we consider it a match if we have at least one matching key.
                 return false;
             }
         },
@@ -230,16 +423,20 @@ fn event_matches_spec(event: &ContractEvent, spec_entry: &ScSpecEntry) -> bool {
                 return false;
             }
 
+            if strict && vec_entries.len() != data_params.len() {
+                return false; // Strict mode requires an exact element-for-element match
+            }
+
             // We'll be lenient with size mismatches, but we still need data
             let matching_count = std::cmp::min(vec_entries.len(), data_params.len());
-            
+
             // Check the types of the elements we have
             for i in 0..matching_count {
-                if !sc_val_matches_spec_type(&vec_entries[i], &data_params[i].type_) {
+                if !sc_val_matches_spec_type(&vec_entries[i], &data_params[i].type_, specs) {
                     return false; // Type mismatch for an element in the vec
                 }
             }
-            
+
             // We require at least one matching element
             if matching_count == 0 {
                 return false;
@@ -251,8 +448,165 @@ fn event_matches_spec(event: &ContractEvent, spec_entry: &ScSpecEntry) -> bool {
     true
 }
 
+/// Scores how specifically `spec_entry` matches `event`, for disambiguating between
+/// several specs that all satisfy `event_matches_spec`'s lenient rules. Only
+/// meaningful when `event_matches_spec(event, spec_entry, specs)` is already `true`.
+///
+/// Scoring: +2 per topic param, +2 for the SingleValue data param, +2 per checked
+/// Vec element, +1 per present Map key, 0 for params that the lenient Map/Vec rules
+/// allowed to be skipped. Option-typed params score the same as any other.
+pub(crate) fn score_spec_match(event: &ContractEvent, spec_entry: &ScSpecEntry, specs: &SpecRegistry) -> i32 {
+    let ContractEventBody::V0(event_body) = &event.body;
+    let ScSpecEntry::EventV0(spec) = spec_entry else {
+        return 0;
+    };
+
+    let mut score = 0;
+
+    let mut topic_params: Vec<&ScSpecEventParamV0> = Vec::new();
+    let mut data_params: Vec<&ScSpecEventParamV0> = Vec::new();
+    for param in spec.params.iter() {
+        match param.location {
+            ScSpecEventParamLocationV0::TopicList => topic_params.push(param),
+            ScSpecEventParamLocationV0::Data => data_params.push(param),
+            _ => {},
+        }
+    }
+
+    // Every topic param is required to match by `event_matches_spec`, so each one
+    // contributes a concrete-match point.
+    score += 2 * topic_params.len() as i32;
+
+    match spec.data_format {
+        ScSpecEventDataFormat::SingleValue => {
+            if data_params.len() == 1 {
+                score += 2;
+            }
+        },
+        ScSpecEventDataFormat::Map => {
+            if let ScVal::Map(Some(map_entries)) = &event_body.data {
+                let event_map: HashMap<String, &ScVal> = map_entries
+                    .iter()
+                    .filter_map(|e| match &e.key {
+                        ScVal::Symbol(s) => Some((s.to_string(), &e.val)),
+                        _ => None,
+                    })
+                    .collect();
+                for param in &data_params {
+                    if event_map.contains_key(&param.name.to_string()) {
+                        score += 1; // present Map key
+                    }
+                    // Missing keys are the lenient skip case: 0 points.
+                }
+            }
+        },
+        ScSpecEventDataFormat::Vec => {
+            if let ScVal::Vec(Some(vec_entries)) = &event_body.data {
+                let matching_count = std::cmp::min(vec_entries.len(), data_params.len());
+                score += 2 * matching_count as i32;
+                // Any params beyond `matching_count` were leniently skipped: 0 points.
+            }
+        },
+    }
+
+    score
+}
+
+/// Describes exactly which keys/elements a lenient match skipped, so the caller can
+/// surface *why* a lenient match succeeded. Assumes `event_matches_spec` already
+/// returned `true` for this (event, spec) pair; in strict mode nothing is ever
+/// skipped, so this always returns an empty list there.
+fn validation_warnings(
+    event: &ContractEvent,
+    spec_entry: &ScSpecEntry,
+    strict: bool,
+) -> Vec<String> {
+    if strict {
+        return Vec::new();
+    }
+
+    let ContractEventBody::V0(event_body) = &event.body;
+    let ScSpecEntry::EventV0(spec) = spec_entry else {
+        return Vec::new();
+    };
+
+    let data_params: Vec<&ScSpecEventParamV0> = spec
+        .params
+        .iter()
+        .filter(|p| p.location == ScSpecEventParamLocationV0::Data)
+        .collect();
+
+    let mut warnings = Vec::new();
+
+    match spec.data_format {
+        ScSpecEventDataFormat::SingleValue => {},
+        ScSpecEventDataFormat::Map => {
+            if let ScVal::Map(Some(map_entries)) = &event_body.data {
+                let event_map: HashMap<String, &ScVal> = map_entries
+                    .iter()
+                    .filter_map(|e| match &e.key {
+                        ScVal::Symbol(s) => Some((s.to_string(), &e.val)),
+                        _ => None,
+                    })
+                    .collect();
+                for param in &data_params {
+                    let key = param.name.to_string();
+                    if !event_map.contains_key(&key) {
+                        warnings.push(format!(
+                            "data map key {key:?} was missing and was leniently skipped"
+                        ));
+                    }
+                }
+                for key in event_map.keys() {
+                    if !data_params.iter().any(|p| p.name.to_string() == *key) {
+                        warnings.push(format!(
+                            "data map key {key:?} is not declared in the spec and was not checked"
+                        ));
+                    }
+                }
+            }
+        },
+        ScSpecEventDataFormat::Vec => {
+            if let ScVal::Vec(Some(vec_entries)) = &event_body.data {
+                if vec_entries.len() != data_params.len() {
+                    warnings.push(format!(
+                        "data vec has {} element(s) but the spec declares {} param(s); only the first {} were checked",
+                        vec_entries.len(),
+                        data_params.len(),
+                        std::cmp::min(vec_entries.len(), data_params.len()),
+                    ));
+                }
+            }
+        },
+    }
+
+    warnings
+}
+
+/// Renders `val` per `format`: the legacy opaque XDR-JSON encoding, or a
+/// self-describing typed encoding shaped by `ty`.
+fn render_param_value(
+    val: &ScVal,
+    ty: &ScSpecTypeDef,
+    format: Format,
+    specs: &SpecRegistry,
+) -> JsonValue {
+    match format {
+        Format::XdrJson => serde_json::to_value(val).unwrap(),
+        // `Display` never reaches `generate_derived_json` (see `main`), but fall
+        // back to the typed rendering rather than leaving the match non-exhaustive.
+        Format::TypedJson | Format::Display => scval_to_typed_json(val, ty, specs),
+    }
+}
+
 // Function to generate a self-describing JSON from event data using spec
-fn generate_derived_json(event: &ContractEvent, spec_entry: &ScSpecEntry) -> JsonValue {
+fn generate_derived_json(
+    event: &ContractEvent,
+    spec_entry: &ScSpecEntry,
+    format: Format,
+    specs: &SpecRegistry,
+    strict: bool,
+) -> JsonValue {
     // Extract event body and spec
     let ContractEventBody::V0(event_body) = &event.body;
     let spec = if let ScSpecEntry::EventV0(spec) = spec_entry {
@@ -267,6 +621,13 @@ fn generate_derived_json(event: &ContractEvent, spec_entry: &ScSpecEntry) -> Jso
     // Add basic information
     result.insert("event_type".to_string(), json!(spec.name.to_string()));
     result.insert("contract_id".to_string(), json!(format!("{:?}", event.contract_id)));
+    result.insert(
+        "validation".to_string(),
+        json!({
+            "mode": if strict { "strict" } else { "lenient" },
+            "warnings": validation_warnings(event, spec_entry, strict),
+        }),
+    );
 
     // Access the event data
     let topics = &event_body.topics;
@@ -305,7 +666,7 @@ fn generate_derived_json(event: &ContractEvent, spec_entry: &ScSpecEntry) -> Jso
             if topic_index < topics.len() {
                 params.insert(
                     param_name,
-                    serde_json::to_value(&topics[topic_index]).unwrap(),
+                    render_param_value(&topics[topic_index], &param.type_, format, specs),
                 );
                 topic_param_count += 1;
             }
@@ -315,14 +676,14 @@ fn generate_derived_json(event: &ContractEvent, spec_entry: &ScSpecEntry) -> Jso
                 ScSpecEventDataFormat::SingleValue => {
                     params.insert(
                         param_name,
-                        serde_json::to_value(&event_body.data).unwrap(),
+                        render_param_value(&event_body.data, &param.type_, format, specs),
                     );
                 },
                 ScSpecEventDataFormat::Map => {
                     if let Some(val) = map_data_entries.get(&param_name) {
                         params.insert(
                             param_name,
-                            serde_json::to_value(val).unwrap(),
+                            render_param_value(val, &param.type_, format, specs),
                         );
                     }
                 },
@@ -330,7 +691,12 @@ fn generate_derived_json(event: &ContractEvent, spec_entry: &ScSpecEntry) -> Jso
                     if data_param_count < vec_data_entries.len() {
                         params.insert(
                             param_name,
-                            serde_json::to_value(vec_data_entries[data_param_count]).unwrap(),
+                            render_param_value(
+                                vec_data_entries[data_param_count],
+                                &param.type_,
+                                format,
+                                specs,
+                            ),
                         );
                         data_param_count += 1;
                     }
@@ -340,7 +706,8 @@ fn generate_derived_json(event: &ContractEvent, spec_entry: &ScSpecEntry) -> Jso
     }
     
     // Include any additional data entries not explicitly defined in the spec
-    // (Only for Map format, as we want to be lenient in matching)
+    // (Only for Map format, as we want to be lenient in matching). These have no
+    // spec type to decode against, so they're always rendered as raw XDR-JSON.
     if spec.data_format == ScSpecEventDataFormat::Map {
         for (key, val) in &map_data_entries {
             if !params.contains_key(key) {
@@ -360,8 +727,12 @@ fn generate_derived_json(event: &ContractEvent, spec_entry: &ScSpecEntry) -> Jso
 
 impl Args {
     fn event(&self) -> Result<ContractEvent, Box<dyn std::error::Error>> {
+        let path = self
+            .event
+            .as_ref()
+            .expect("--event is required unless --generate is set");
         Ok(serde_json::from_reader::<_, ContractEvent>(File::open(
-            &self.event,
+            path,
         )?)?)
     }
 
@@ -376,3 +747,30 @@ impl Args {
             .collect::<Result<Vec<_>, _>>()?)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_fixtures::{contract_event, event_spec, map_val, param, symbol};
+
+    #[test]
+    fn strict_mode_rejects_a_missing_required_map_key_that_lenient_mode_allows() {
+        let spec = event_spec(
+            "evt",
+            &["evt"],
+            vec![
+                param("a", ScSpecTypeDef::U32, ScSpecEventParamLocationV0::Data),
+                param("b", ScSpecTypeDef::U32, ScSpecEventParamLocationV0::Data),
+            ],
+            ScSpecEventDataFormat::Map,
+        );
+        let event = contract_event(
+            vec![ScVal::Symbol(symbol("evt"))],
+            map_val(vec![("a", ScVal::U32(1))]),
+        );
+        let registry: SpecRegistry = HashMap::new();
+
+        assert!(event_matches_spec(&event, &spec, &registry, false));
+        assert!(!event_matches_spec(&event, &spec, &registry, true));
+    }
+}
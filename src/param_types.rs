@@ -1,90 +1,43 @@
-use std::iter::once;
+use stellar_xdr::curr::{ScSpecType, ScValType};
 
-use stellar_xdr::curr::{
-    ContractEvent, ContractEventBody, ScSpecEntry, ScSpecEventDataFormat,
-    ScSpecEventParamLocationV0, ScSpecType, ScValType,
-};
-
-pub trait ParamTypes {
-    fn param_types(&self) -> Vec<ScValType>;
-}
-
-impl ParamTypes for ContractEvent {
-    fn param_types(&self) -> Vec<ScValType> {
-        match &self.body {
-            ContractEventBody::V0(body) => body
-                .topics
-                .iter()
-                .map(|t| t.discriminant())
-                .chain(once(body.data.discriminant()))
-                .collect(),
-        }
-    }
-}
-
-impl ParamTypes for ScSpecEntry {
-    fn param_types(&self) -> Vec<ScValType> {
-        match self {
-            ScSpecEntry::EventV0(s) => {
-                let prefix_types = s.prefix_topics.iter().map(|_| ScValType::Symbol);
-                let topic_types = s.params.iter().filter_map(|p| match &p.location {
-                    ScSpecEventParamLocationV0::TopicList => {
-                        Some(sc_spec_type_to_sc_val_type(p.type_.discriminant()))
-                    }
-                    _ => None,
-                });
-                let data_types = match s.data_format {
-                    ScSpecEventDataFormat::SingleValue => s
-                        .params
-                        .iter()
-                        .filter_map(|p| match &p.location {
-                            ScSpecEventParamLocationV0::Data => {
-                                Some(sc_spec_type_to_sc_val_type(p.type_.discriminant()))
-                            }
-                            _ => None,
-                        })
-                        .next()
-                        .unwrap_or(ScValType::Void),
-                    ScSpecEventDataFormat::Vec => ScValType::Vec,
-                    ScSpecEventDataFormat::Map => ScValType::Map,
-                };
-                prefix_types
-                    .chain(topic_types)
-                    .chain(once(data_types))
-                    .collect()
-            }
-            _ => Vec::new(),
-        }
-    }
-}
-
-fn sc_spec_type_to_sc_val_type(t: ScSpecType) -> ScValType {
+/// Maps a spec-level type discriminant to the value-level discriminant it decodes
+/// to, used to pick generated field types (see `bindings`). `ScSpecType` only carries
+/// the outer discriminant (no nested type info), so a few mappings are necessarily
+/// approximate:
+/// - `Option`/`Result` wrap another type that isn't visible here, so they fall back
+///   to `Void` (the `None`/no-value case); `sc_val_matches_spec_type` does the real,
+///   precise check once the full `ScSpecTypeDef` (not just its discriminant) is in
+///   hand.
+/// - `Tuple` and `BytesN` are encoded on the wire as `Vec`/`Bytes` respectively.
+/// - `Udt` could be a struct (`Map`), union/enum (`Vec`/`U32`), so it's approximated
+///   as `Map`, the most common case; `sc_val_matches_udt` resolves it precisely.
+pub(crate) fn sc_spec_type_to_sc_val_type(t: ScSpecType) -> ScValType {
     match t {
-        ScSpecType::Val => todo!(),
-        ScSpecType::Bool => todo!(),
-        ScSpecType::Void => todo!(),
-        ScSpecType::Error => todo!(),
+        ScSpecType::Val => ScValType::Void,
+        ScSpecType::Bool => ScValType::Bool,
+        ScSpecType::Void => ScValType::Void,
+        ScSpecType::Error => ScValType::Error,
         ScSpecType::U32 => ScValType::U32,
-        ScSpecType::I32 => todo!(),
+        ScSpecType::I32 => ScValType::I32,
         ScSpecType::U64 => ScValType::U64,
-        ScSpecType::I64 => todo!(),
-        ScSpecType::Timepoint => todo!(),
-        ScSpecType::Duration => todo!(),
-        ScSpecType::U128 => todo!(),
+        ScSpecType::I64 => ScValType::I64,
+        ScSpecType::Timepoint => ScValType::Timepoint,
+        ScSpecType::Duration => ScValType::Duration,
+        ScSpecType::U128 => ScValType::U128,
         ScSpecType::I128 => ScValType::I128,
-        ScSpecType::U256 => todo!(),
-        ScSpecType::I256 => todo!(),
-        ScSpecType::Bytes => todo!(),
+        ScSpecType::U256 => ScValType::U256,
+        ScSpecType::I256 => ScValType::I256,
+        ScSpecType::Bytes => ScValType::Bytes,
         ScSpecType::String => ScValType::String,
         ScSpecType::Symbol => ScValType::Symbol,
         ScSpecType::Address => ScValType::Address,
-        ScSpecType::MuxedAddress => todo!(),
-        ScSpecType::Option => todo!(),
-        ScSpecType::Result => todo!(),
+        ScSpecType::MuxedAddress => ScValType::Address,
+        ScSpecType::Option => ScValType::Void,
+        ScSpecType::Result => ScValType::Void,
         ScSpecType::Vec => ScValType::Vec,
         ScSpecType::Map => ScValType::Map,
-        ScSpecType::Tuple => todo!(),
-        ScSpecType::BytesN => todo!(),
-        ScSpecType::Udt => todo!(),
+        ScSpecType::Tuple => ScValType::Vec,
+        ScSpecType::BytesN => ScValType::Bytes,
+        ScSpecType::Udt => ScValType::Map,
     }
 }
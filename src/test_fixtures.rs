@@ -0,0 +1,76 @@
+//! Shared builders for the behavior tests under `#[cfg(test)]` in the other
+//! modules, so each test doesn't have to hand-roll XDR values.
+#![cfg(test)]
+
+use stellar_xdr::curr::{
+    ContractEvent, ContractEventBody, ContractEventType, ContractEventV0, ExtensionPoint, Int128Parts,
+    ScMap, ScMapEntry, ScSpecEntry, ScSpecEventDataFormat, ScSpecEventParamLocationV0,
+    ScSpecEventParamV0, ScSpecEventV0, ScSpecTypeDef, ScSymbol, ScVal, StringM,
+};
+
+pub fn symbol(s: &str) -> ScSymbol {
+    s.try_into().unwrap()
+}
+
+pub fn param(name: &str, ty: ScSpecTypeDef, location: ScSpecEventParamLocationV0) -> ScSpecEventParamV0 {
+    ScSpecEventParamV0 {
+        doc: StringM::default(),
+        name: name.try_into().unwrap(),
+        type_: ty,
+        location,
+    }
+}
+
+pub fn event_spec(
+    name: &str,
+    prefix_topics: &[&str],
+    params: Vec<ScSpecEventParamV0>,
+    data_format: ScSpecEventDataFormat,
+) -> ScSpecEntry {
+    ScSpecEntry::EventV0(ScSpecEventV0 {
+        doc: StringM::default(),
+        lib: StringM::default(),
+        name: name.try_into().unwrap(),
+        prefix_topics: prefix_topics
+            .iter()
+            .map(|s| symbol(s))
+            .collect::<Vec<_>>()
+            .try_into()
+            .unwrap(),
+        params: params.try_into().unwrap(),
+        data_format,
+    })
+}
+
+pub fn contract_event(topics: Vec<ScVal>, data: ScVal) -> ContractEvent {
+    ContractEvent {
+        ext: ExtensionPoint::V0,
+        contract_id: None,
+        type_: ContractEventType::Contract,
+        body: ContractEventBody::V0(ContractEventV0 {
+            topics: topics.try_into().unwrap(),
+            data,
+        }),
+    }
+}
+
+pub fn map_val(entries: Vec<(&str, ScVal)>) -> ScVal {
+    ScVal::Map(Some(ScMap(
+        entries
+            .into_iter()
+            .map(|(k, v)| ScMapEntry {
+                key: ScVal::Symbol(symbol(k)),
+                val: v,
+            })
+            .collect::<Vec<_>>()
+            .try_into()
+            .unwrap(),
+    )))
+}
+
+pub fn i128_val(n: i128) -> ScVal {
+    ScVal::I128(Int128Parts {
+        hi: (n >> 64) as i64,
+        lo: n as u64,
+    })
+}